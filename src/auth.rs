@@ -0,0 +1,120 @@
+use crate::error::ApiError;
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+
+// JWT claims: the authenticated user id (`sub`), issued-at, and expiry.
+#[derive(Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub iat: usize,
+    pub exp: usize,
+}
+
+// Sign a JWT for `user_id`, valid for `maxage` minutes.
+pub fn sign_jwt(user_id: i32, secret: &str, maxage: i64) -> Result<String, ApiError> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as usize;
+    let exp = now + (maxage as usize) * 60;
+
+    let claims = Claims {
+        sub: user_id.to_string(),
+        iat: now,
+        exp,
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .map_err(|_| ApiError::Unauthorized)
+}
+
+// Validate a JWT's signature and expiry and return its claims.
+pub fn verify_jwt(token: &str, secret: &str) -> Result<Claims, ApiError> {
+    decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::default(),
+    )
+    .map(|data| data.claims)
+    .map_err(|_| ApiError::Unauthorized)
+}
+
+// Hash a plaintext password for storage.
+pub fn hash_password(password: &str) -> Result<String, ApiError> {
+    bcrypt::hash(password, bcrypt::DEFAULT_COST).map_err(|_| ApiError::Unauthorized)
+}
+
+// Verify a plaintext password against a stored hash.
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    bcrypt::verify(password, hash).unwrap_or(false)
+}
+
+// Pull the bearer token out of a raw `Authorization: Bearer <token>`
+// request header, if present.
+pub fn bearer_token(request: &str) -> Option<&str> {
+    request
+        .lines()
+        .find_map(|line| line.strip_prefix("Authorization: Bearer "))
+        .map(|token| token.trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    #[test]
+    fn sign_then_verify_round_trips_the_user_id() {
+        let token = sign_jwt(42, SECRET, 60).unwrap();
+        let claims = verify_jwt(&token, SECRET).unwrap();
+
+        assert_eq!(claims.sub, "42");
+    }
+
+    #[test]
+    fn verify_rejects_an_expired_token() {
+        let claims = Claims {
+            sub: "42".to_string(),
+            iat: 0,
+            exp: 0,
+        };
+        let token = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(SECRET.as_bytes()),
+        )
+        .unwrap();
+
+        assert!(matches!(verify_jwt(&token, SECRET), Err(ApiError::Unauthorized)));
+    }
+
+    #[test]
+    fn verify_rejects_a_token_signed_with_a_different_secret() {
+        let token = sign_jwt(42, SECRET, 60).unwrap();
+
+        assert!(matches!(
+            verify_jwt(&token, "wrong-secret"),
+            Err(ApiError::Unauthorized)
+        ));
+    }
+
+    #[test]
+    fn bearer_token_extracts_the_token_from_the_authorization_header() {
+        let request = "PUT /users/1 HTTP/1.1\r\nAuthorization: Bearer abc123\r\n\r\n{}";
+
+        assert_eq!(bearer_token(request), Some("abc123"));
+    }
+
+    #[test]
+    fn bearer_token_is_none_when_the_header_is_missing_or_malformed() {
+        let missing = "PUT /users/1 HTTP/1.1\r\n\r\n{}";
+        assert_eq!(bearer_token(missing), None);
+
+        let wrong_scheme = "PUT /users/1 HTTP/1.1\r\nAuthorization: Basic abc123\r\n\r\n{}";
+        assert_eq!(bearer_token(wrong_scheme), None);
+    }
+}
@@ -0,0 +1,81 @@
+use std::env;
+use std::fmt;
+
+// Default bind address and pool size used when the corresponding
+// environment variables are unset.
+const DEFAULT_SERVER_HOST: &str = "0.0.0.0";
+const DEFAULT_SERVER_PORT: &str = "8080";
+const DEFAULT_POOL_SIZE: u32 = 10;
+const DEFAULT_WORKER_THREADS: usize = 4;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Missing(&'static str),
+    Invalid(&'static str, String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Missing(name) => write!(f, "missing required env var {}", name),
+            ConfigError::Invalid(name, value) => {
+                write!(f, "invalid value for {}: {}", name, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// Server configuration, read from the environment (via `.env` or the
+// process environment) instead of being hardcoded.
+pub struct Config {
+    pub database_url: String,
+    pub server_address: String,
+    pub pool_size: u32,
+    pub worker_threads: usize,
+    pub jwt_secret: String,
+    pub jwt_maxage: i64,
+}
+
+impl Config {
+    pub fn init() -> Result<Self, ConfigError> {
+        let database_url =
+            env::var("DATABASE_URL").map_err(|_| ConfigError::Missing("DATABASE_URL"))?;
+
+        let server_host =
+            env::var("SERVER_HOST").unwrap_or_else(|_| DEFAULT_SERVER_HOST.to_string());
+        let server_port =
+            env::var("SERVER_PORT").unwrap_or_else(|_| DEFAULT_SERVER_PORT.to_string());
+        let server_address = format!("{}:{}", server_host, server_port);
+
+        let pool_size = match env::var("POOL_SIZE") {
+            Ok(value) => value
+                .parse()
+                .map_err(|_| ConfigError::Invalid("POOL_SIZE", value))?,
+            Err(_) => DEFAULT_POOL_SIZE,
+        };
+
+        let worker_threads = match env::var("WORKER_THREADS") {
+            Ok(value) => value
+                .parse()
+                .map_err(|_| ConfigError::Invalid("WORKER_THREADS", value))?,
+            Err(_) => DEFAULT_WORKER_THREADS,
+        };
+
+        let jwt_secret = env::var("JWT_SECRET").map_err(|_| ConfigError::Missing("JWT_SECRET"))?;
+        let jwt_maxage = env::var("JWT_MAXAGE")
+            .map_err(|_| ConfigError::Missing("JWT_MAXAGE"))?
+            .parse()
+            .map_err(|_| ConfigError::Invalid("JWT_MAXAGE", "not an integer".to_string()))?;
+
+        Ok(Config {
+            database_url,
+            server_address,
+            pool_size,
+            worker_threads,
+            jwt_secret,
+            jwt_maxage,
+        })
+    }
+}
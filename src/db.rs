@@ -0,0 +1,159 @@
+use postgres::{Client, NoTls};
+use postgres::Error as PostgresError;
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+
+// A minimal connection pool for the sync `postgres` client.
+//
+// Idle clients are kept in a `VecDeque` behind a `Mutex`. `get()` pops an
+// idle client, or opens a new one if the pool hasn't reached `max_size`
+// connections yet, blocking callers when the pool is saturated. Returned
+// guards run a cheap liveness check on `Drop` and discard the connection
+// instead of recycling it if that check fails.
+pub struct Pool {
+    db_url: String,
+    max_size: u32,
+    inner: Mutex<Inner>,
+    condvar: Condvar,
+}
+
+struct Inner {
+    idle: VecDeque<Client>,
+    total: u32,
+}
+
+impl Pool {
+    pub fn new(db_url: impl Into<String>, max_size: u32) -> Self {
+        Pool {
+            db_url: db_url.into(),
+            max_size,
+            inner: Mutex::new(Inner {
+                idle: VecDeque::new(),
+                total: 0,
+            }),
+            condvar: Condvar::new(),
+        }
+    }
+
+    pub fn get(self: &Arc<Self>) -> Result<PooledConnection, PostgresError> {
+        let mut inner = self.inner.lock().unwrap();
+
+        loop {
+            if let Some(client) = inner.idle.pop_front() {
+                return Ok(PooledConnection {
+                    pool: Arc::clone(self),
+                    client: Some(client),
+                });
+            }
+
+            if inner.total < self.max_size {
+                // Reserve the slot, then drop the lock before the
+                // network round-trip below so callers that just want an
+                // idle connection aren't blocked behind it.
+                inner.total += 1;
+                drop(inner);
+
+                return match Client::connect(&self.db_url, NoTls) {
+                    Ok(client) => Ok(PooledConnection {
+                        pool: Arc::clone(self),
+                        client: Some(client),
+                    }),
+                    Err(e) => {
+                        self.drop_broken();
+                        Err(e)
+                    }
+                };
+            }
+
+            inner = self.condvar.wait(inner).unwrap();
+        }
+    }
+
+    fn recycle(&self, mut client: Client) {
+        let mut inner = self.inner.lock().unwrap();
+
+        // Discard connections that no longer respond instead of handing a
+        // dead socket to the next caller.
+        if client.is_closed() || client.simple_query("SELECT 1").is_err() {
+            inner.total -= 1;
+        } else {
+            inner.idle.push_back(client);
+        }
+
+        self.condvar.notify_one();
+    }
+
+    fn drop_broken(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.total -= 1;
+        self.condvar.notify_one();
+    }
+}
+
+// A pooled `Client` on loan from a `Pool`. Derefs to `Client`; returned to
+// the pool (or discarded, if it's no longer alive) when dropped.
+pub struct PooledConnection {
+    pool: Arc<Pool>,
+    client: Option<Client>,
+}
+
+impl std::ops::Deref for PooledConnection {
+    type Target = Client;
+
+    fn deref(&self) -> &Client {
+        self.client.as_ref().unwrap()
+    }
+}
+
+impl std::ops::DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Client {
+        self.client.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        match self.client.take() {
+            Some(client) => self.pool.recycle(client),
+            None => self.pool.drop_broken(),
+        }
+    }
+}
+
+// These hit a real Postgres instance (no mocking of the `postgres` crate),
+// so they're opt-in: `DATABASE_URL=... cargo test -- --ignored`.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_pool(max_size: u32) -> Option<Arc<Pool>> {
+        std::env::var("DATABASE_URL")
+            .ok()
+            .map(|db_url| Arc::new(Pool::new(db_url, max_size)))
+    }
+
+    #[test]
+    #[ignore = "requires a running Postgres at DATABASE_URL"]
+    fn get_recycles_a_dropped_connection_instead_of_opening_a_new_one() {
+        let pool = test_pool(1).expect("DATABASE_URL must be set to run this test");
+
+        drop(pool.get().unwrap());
+        assert_eq!(pool.inner.lock().unwrap().idle.len(), 1);
+        assert_eq!(pool.inner.lock().unwrap().total, 1);
+
+        let _conn = pool.get().unwrap();
+        assert_eq!(pool.inner.lock().unwrap().idle.len(), 0);
+        assert_eq!(pool.inner.lock().unwrap().total, 1);
+    }
+
+    #[test]
+    #[ignore = "requires a running Postgres at DATABASE_URL"]
+    fn get_opens_new_connections_up_to_max_size() {
+        let pool = test_pool(2).expect("DATABASE_URL must be set to run this test");
+
+        let _first = pool.get().unwrap();
+        let _second = pool.get().unwrap();
+
+        assert_eq!(pool.inner.lock().unwrap().total, 2);
+    }
+}
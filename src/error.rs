@@ -0,0 +1,69 @@
+use std::fmt;
+
+// Postgres unique_violation error code.
+const UNIQUE_VIOLATION: &str = "23505";
+
+// Errors a handler can fail with, mapped to the right HTTP status line
+// instead of collapsing everything into a panic or a generic 500.
+#[derive(Debug)]
+pub enum ApiError {
+    Db(postgres::Error),
+    Json(serde_json::Error),
+    NotFound,
+    BadRequest,
+    Conflict,
+    Unauthorized,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApiError::Db(e) => write!(f, "database error: {}", e),
+            ApiError::Json(e) => write!(f, "invalid json: {}", e),
+            ApiError::NotFound => write!(f, "not found"),
+            ApiError::BadRequest => write!(f, "bad request"),
+            ApiError::Conflict => write!(f, "conflict"),
+            ApiError::Unauthorized => write!(f, "unauthorized"),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+impl From<postgres::Error> for ApiError {
+    fn from(e: postgres::Error) -> Self {
+        let is_unique_violation = e
+            .as_db_error()
+            .is_some_and(|db_error| db_error.code().code() == UNIQUE_VIOLATION);
+
+        if is_unique_violation {
+            ApiError::Conflict
+        } else {
+            ApiError::Db(e)
+        }
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(e: serde_json::Error) -> Self {
+        ApiError::Json(e)
+    }
+}
+
+impl ApiError {
+    // The status line to send back for this error, and a short message
+    // describing it.
+    pub fn response(&self) -> (String, String) {
+        let status_line = match self {
+            ApiError::BadRequest | ApiError::Json(_) => {
+                "HTTP/1.1 400 BAD REQUEST\r\n\r\n"
+            }
+            ApiError::Unauthorized => "HTTP/1.1 401 UNAUTHORIZED\r\n\r\n",
+            ApiError::NotFound => "HTTP/1.1 404 NOT FOUND\r\n\r\n",
+            ApiError::Conflict => "HTTP/1.1 409 CONFLICT\r\n\r\n",
+            ApiError::Db(_) => "HTTP/1.1 500 INTERNAL ERROR\r\n\r\n",
+        };
+
+        (status_line.to_string(), self.to_string())
+    }
+}
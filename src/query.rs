@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+// Decode a single percent-encoded URL component (e.g. `foo%40bar` -> `foo@bar`).
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => {
+                decoded.push(b' ');
+                i += 1;
+            }
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 3;
+                    }
+                    None => {
+                        decoded.push(bytes[i]);
+                        i += 1;
+                    }
+                }
+            }
+            byte => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+// Parse the query string of a request line (the part of the path after
+// `?`) into a map of decoded key/value pairs. Unknown parameters are left
+// for the caller to ignore.
+pub fn parse(request: &str) -> HashMap<String, String> {
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+    let query = match path.split_once('?') {
+        Some((_, query)) => query,
+        None => return HashMap::new(),
+    };
+
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (percent_decode(key), percent_decode(value)),
+            None => (percent_decode(pair), String::new()),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_limit_offset_sort_and_filter_params() {
+        let params = parse("GET /users?limit=20&offset=40&sort=name&email=foo@bar HTTP/1.1");
+
+        assert_eq!(params.get("limit"), Some(&"20".to_string()));
+        assert_eq!(params.get("offset"), Some(&"40".to_string()));
+        assert_eq!(params.get("sort"), Some(&"name".to_string()));
+        assert_eq!(params.get("email"), Some(&"foo@bar".to_string()));
+    }
+
+    #[test]
+    fn decodes_percent_encoded_keys_and_values() {
+        let params = parse("GET /users?name=Jane%20Doe&email=a%40b.com HTTP/1.1");
+
+        assert_eq!(params.get("name"), Some(&"Jane Doe".to_string()));
+        assert_eq!(params.get("email"), Some(&"a@b.com".to_string()));
+    }
+
+    #[test]
+    fn treats_plus_as_space() {
+        let params = parse("GET /users?name=Jane+Doe HTTP/1.1");
+
+        assert_eq!(params.get("name"), Some(&"Jane Doe".to_string()));
+    }
+
+    #[test]
+    fn falls_back_to_the_literal_percent_on_an_invalid_escape() {
+        let params = parse("GET /users?name=100%+off HTTP/1.1");
+
+        assert_eq!(params.get("name"), Some(&"100% off".to_string()));
+    }
+
+    #[test]
+    fn keeps_unknown_params_for_the_caller_and_handles_a_missing_query_string() {
+        let params = parse("GET /users?color=blue HTTP/1.1");
+        assert_eq!(params.get("color"), Some(&"blue".to_string()));
+
+        let params = parse("GET /users HTTP/1.1");
+        assert!(params.is_empty());
+    }
+
+    #[test]
+    fn value_less_key_decodes_to_an_empty_string() {
+        let params = parse("GET /users?name HTTP/1.1");
+
+        assert_eq!(params.get("name"), Some(&String::new()));
+    }
+}
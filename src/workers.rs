@@ -0,0 +1,79 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+// A fixed-size pool of worker threads that pull jobs off a shared channel.
+// Submitting a job when every worker is busy just queues it on the
+// channel; the next free worker picks it up.
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    sender: mpsc::Sender<Job>,
+}
+
+impl ThreadPool {
+    pub fn new(size: usize) -> Self {
+        assert!(size > 0);
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..size)
+            .map(|id| Worker::new(id, Arc::clone(&receiver)))
+            .collect();
+
+        ThreadPool { workers, sender }
+    }
+
+    pub fn execute<F>(&self, job: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        // If every worker has hung up (which should not happen in
+        // practice, since workers never exit) the job is simply dropped.
+        let _ = self.sender.send(Box::new(job));
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        for worker in &mut self.workers {
+            if let Some(handle) = worker.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+struct Worker {
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl Worker {
+    fn new(id: usize, receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Self {
+        let handle = thread::spawn(move || loop {
+            let job = receiver.lock().unwrap().recv();
+
+            match job {
+                Ok(job) => {
+                    // Catch a panicking job instead of letting it unwind
+                    // the thread: once a worker exits, it stops pulling
+                    // jobs off the shared channel for good.
+                    if std::panic::catch_unwind(std::panic::AssertUnwindSafe(job)).is_err() {
+                        eprintln!("Worker {} recovered from a panicking job", id);
+                    }
+                }
+                Err(_) => {
+                    // Sender dropped: no more jobs will ever arrive.
+                    println!("Worker {} shutting down", id);
+                    break;
+                }
+            }
+        });
+
+        Worker {
+            handle: Some(handle),
+        }
+    }
+}